@@ -0,0 +1,80 @@
+// aarch64 run-time feature detection.
+//
+// On Linux/Android this reads the HWCAP/HWCAP2 bits exposed via the auxiliary vector (the same
+// mechanism `std::arch::is_aarch64_feature_detected!` uses internally). On other aarch64 targets
+// that have no HWCAP equivalent (FreeBSD/NetBSD/OpenBSD), this falls back to the `aarch64_aa64reg`
+// backend, which reads the emulated ID registers directly.
+//
+// Refs:
+// - https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
+
+include!("common.rs");
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod os {
+    use super::CpuInfo;
+
+    // Linux arch/arm64/include/uapi/asm/hwcap.h
+    const HWCAP_ATOMICS: u64 = 1 << 8; // FEAT_LSE
+    const HWCAP_USCAT: u64 = 1 << 25; // FEAT_LSE2 (ID_AA64MMFR2_EL1.AT)
+    const HWCAP2_LRCPC3: u64 = 1 << 46; // FEAT_LRCPC3
+    const HWCAP2_LSE128: u64 = 1 << 47; // FEAT_LSE128
+
+    // AT_HWCAP/AT_HWCAP2, as used by getauxval.
+    const AT_HWCAP: core::ffi::c_ulong = 16;
+    const AT_HWCAP2: core::ffi::c_ulong = 26;
+
+    extern "C" {
+        fn getauxval(r#type: core::ffi::c_ulong) -> core::ffi::c_ulong;
+    }
+
+    pub(super) fn detect(info: &mut CpuInfo) {
+        // SAFETY: getauxval is safe to call with a supported AT_* type on Linux/Android.
+        let hwcap = unsafe { getauxval(AT_HWCAP) } as u64;
+        // SAFETY: same as above.
+        let hwcap2 = unsafe { getauxval(AT_HWCAP2) } as u64;
+
+        if hwcap & HWCAP_ATOMICS != 0 {
+            info.set(CpuInfo::HAS_LSE);
+        }
+        if hwcap & HWCAP_USCAT != 0 {
+            info.set(CpuInfo::HAS_LSE2);
+        }
+        if hwcap2 & HWCAP2_LRCPC3 != 0 {
+            info.set(CpuInfo::HAS_RCPC3);
+        }
+        if hwcap2 & HWCAP2_LSE128 != 0 {
+            info.set(CpuInfo::HAS_LSE128);
+        }
+    }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+#[path = "aarch64_aa64reg.rs"]
+mod aarch64_aa64reg;
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+mod os {
+    use super::CpuInfo;
+
+    pub(super) fn detect(info: &mut CpuInfo) {
+        let _ = super::aarch64_aa64reg::detect(info);
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+)))]
+mod os {
+    use super::CpuInfo;
+
+    pub(super) fn detect(_info: &mut CpuInfo) {}
+}
+
+fn _detect(info: &mut CpuInfo) {
+    os::detect(info);
+}
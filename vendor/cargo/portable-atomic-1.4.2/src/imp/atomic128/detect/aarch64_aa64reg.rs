@@ -0,0 +1,196 @@
+// Run-time feature detection on aarch64 via emulated ID register reads.
+//
+// As of nightly 2023-08-24, is_aarch64_feature_detected always uses Linux-specific
+// mechanisms (HWCAP/AT_HWCAP). On BSD-family targets (FreeBSD/NetBSD/OpenBSD) there is no
+// widely available HWCAP equivalent, but the kernel emulates reads of the `MRS`-accessible ID
+// registers (`ID_AA64ISAR0_EL1`, `ID_AA64ISAR1_EL1`, `ID_AA64MMFR2_EL1`) for unprivileged
+// userspace, exposed to userspace through `sysctl`/`sysctlbyname`. This module reads those
+// emulated registers and decodes the ID fields relevant to atomics, instead of going through
+// HWCAP. As elsewhere in this directory, we declare the handful of libc functions we need
+// directly rather than depending on the `libc` crate.
+//
+// Refs:
+// - https://developer.arm.com/documentation/ddi0601/2023-06/AArch64-Registers/ID-AA64ISAR0-EL1--AArch64-Instruction-Set-Attribute-Register-0
+// - https://developer.arm.com/documentation/ddi0601/2023-06/AArch64-Registers/ID-AA64ISAR1-EL1--AArch64-Instruction-Set-Attribute-Register-1
+// - https://developer.arm.com/documentation/ddi0601/2023-06/AArch64-Registers/ID-AA64MMFR2-EL1--AArch64-Memory-Model-Feature-Register-2
+// - FreeBSD: https://cgit.freebsd.org/src/tree/sys/arm64/arm64/identcpu.c
+// - OpenBSD: https://cvsweb.openbsd.org/cgi-bin/cvsweb/src/sys/arch/arm64/arm64/machdep.c
+// - NetBSD: https://nxr.netbsd.org/xref/src/sys/arch/aarch64/aarch64/cpu_machdep.c
+
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+
+use super::CpuInfo;
+
+type c_size_t = usize;
+
+// ID_AA64ISAR0_EL1.Atomic, bits [23:20].
+const ISAR0_ATOMIC_SHIFT: u32 = 20;
+// ID_AA64ISAR0_EL1.Atomic == 0b0010: FEAT_LSE.
+const ISAR0_ATOMIC_LSE: u64 = 0b0010;
+// ID_AA64ISAR0_EL1.Atomic == 0b0011: FEAT_LSE128.
+const ISAR0_ATOMIC_LSE128: u64 = 0b0011;
+
+// ID_AA64ISAR1_EL1.LRCPC, bits [23:20].
+const ISAR1_LRCPC_SHIFT: u32 = 20;
+// ID_AA64ISAR1_EL1.LRCPC == 0b0011: FEAT_LRCPC3.
+const ISAR1_LRCPC3: u64 = 0b0011;
+
+// ID_AA64MMFR2_EL1.AT, bits [35:32].
+const MMFR2_AT_SHIFT: u32 = 32;
+
+#[inline]
+fn field(reg: u64, shift: u32) -> u64 {
+    (reg >> shift) & 0b1111
+}
+
+/// Populates the LSE/LSE2/LSE128/LRCPC3 bits in `info` from the emulated aarch64 ID registers.
+///
+/// Returns `Err(())` if any of the registers could not be read (e.g. the sysctl is missing on
+/// this kernel version), leaving `info` untouched.
+pub(super) fn detect(info: &mut CpuInfo) -> Result<(), ()> {
+    let isar0 = id_aa64isar0_el1()?;
+    let isar1 = id_aa64isar1_el1()?;
+    let mmfr2 = id_aa64mmfr2_el1()?;
+
+    let atomic = field(isar0, ISAR0_ATOMIC_SHIFT);
+    if atomic >= ISAR0_ATOMIC_LSE {
+        info.set(CpuInfo::HAS_LSE);
+    }
+    if atomic >= ISAR0_ATOMIC_LSE128 {
+        info.set(CpuInfo::HAS_LSE128);
+    }
+    if field(mmfr2, MMFR2_AT_SHIFT) >= 1 {
+        info.set(CpuInfo::HAS_LSE2);
+    }
+    if field(isar1, ISAR1_LRCPC_SHIFT) >= ISAR1_LRCPC3 {
+        info.set(CpuInfo::HAS_RCPC3);
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd"))]
+extern "C" {
+    fn sysctlbyname(
+        name: *const c_char,
+        oldp: *mut c_void,
+        oldlenp: *mut c_size_t,
+        newp: *const c_void,
+        newlen: c_size_t,
+    ) -> c_int;
+}
+
+#[cfg(target_os = "openbsd")]
+extern "C" {
+    fn sysctl(
+        name: *const c_int,
+        namelen: c_uint,
+        oldp: *mut c_void,
+        oldlenp: *mut c_size_t,
+        newp: *const c_void,
+        newlen: c_size_t,
+    ) -> c_int;
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd"))]
+fn sysctl_u64(name: &str) -> Result<u64, ()> {
+    use std::ffi::CString;
+
+    let name = CString::new(name).map_err(|_| ())?;
+    let mut val: u64 = 0;
+    let mut len = core::mem::size_of::<u64>();
+    // SAFETY: `name` is a valid NUL-terminated string, and `val`/`len` describe a correctly
+    // sized output buffer, satisfying sysctlbyname's out-param contract.
+    let res = unsafe {
+        sysctlbyname(
+            name.as_ptr(),
+            (&mut val as *mut u64).cast::<c_void>(),
+            &mut len,
+            core::ptr::null(),
+            0,
+        )
+    };
+    if res == 0 && len == core::mem::size_of::<u64>() {
+        Ok(val)
+    } else {
+        Err(())
+    }
+}
+
+// OpenBSD's CTL_MACHDEP sysctl namespace (sys/arch/arm64/include/cpu.h).
+#[cfg(target_os = "openbsd")]
+const CTL_MACHDEP: c_int = 7;
+#[cfg(target_os = "openbsd")]
+const CPU_ID_AA64ISAR0: c_int = 2;
+#[cfg(target_os = "openbsd")]
+const CPU_ID_AA64ISAR1: c_int = 3;
+#[cfg(target_os = "openbsd")]
+const CPU_ID_AA64MMFR2: c_int = 7;
+
+#[cfg(target_os = "openbsd")]
+fn sysctl_machdep(id: c_int) -> Result<u64, ()> {
+    let mib = [CTL_MACHDEP, id];
+    let mut val: u64 = 0;
+    let mut len = core::mem::size_of::<u64>();
+    // SAFETY: `mib` is a valid two-element MIB naming a `CTL_MACHDEP` leaf, and `val`/`len`
+    // describe a correctly sized output buffer, satisfying sysctl's out-param contract.
+    let res = unsafe {
+        sysctl(
+            mib.as_ptr(),
+            mib.len() as c_uint,
+            (&mut val as *mut u64).cast::<c_void>(),
+            &mut len,
+            core::ptr::null(),
+            0,
+        )
+    };
+    if res == 0 && len == core::mem::size_of::<u64>() {
+        Ok(val)
+    } else {
+        Err(())
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn id_aa64isar0_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64isar0")
+}
+
+#[cfg(target_os = "freebsd")]
+fn id_aa64isar1_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64isar1")
+}
+
+#[cfg(target_os = "freebsd")]
+fn id_aa64mmfr2_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64mmfr2")
+}
+
+#[cfg(target_os = "netbsd")]
+fn id_aa64isar0_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64isar0")
+}
+
+#[cfg(target_os = "netbsd")]
+fn id_aa64isar1_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64isar1")
+}
+
+#[cfg(target_os = "netbsd")]
+fn id_aa64mmfr2_el1() -> Result<u64, ()> {
+    sysctl_u64("machdep.id_aa64mmfr2")
+}
+
+#[cfg(target_os = "openbsd")]
+fn id_aa64isar0_el1() -> Result<u64, ()> {
+    sysctl_machdep(CPU_ID_AA64ISAR0)
+}
+
+#[cfg(target_os = "openbsd")]
+fn id_aa64isar1_el1() -> Result<u64, ()> {
+    sysctl_machdep(CPU_ID_AA64ISAR1)
+}
+
+#[cfg(target_os = "openbsd")]
+fn id_aa64mmfr2_el1() -> Result<u64, ()> {
+    sysctl_machdep(CPU_ID_AA64MMFR2)
+}
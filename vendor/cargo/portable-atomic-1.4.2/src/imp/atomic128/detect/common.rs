@@ -1,19 +1,44 @@
+/// A snapshot of which 128-bit-atomic-relevant CPU features were detected.
+///
+/// The individual `HAS_*` bit constants and the `has_*` accessors that test them are defined
+/// per-architecture further down this file, gated by `#[cfg(target_arch = "...")]`.
 #[derive(Clone, Copy)]
-pub(crate) struct CpuInfo(u32);
+pub struct CpuInfo(u32);
 
 impl CpuInfo {
     const INIT: u32 = 0;
 
+    /// Creates an empty `CpuInfo` with no features marked as detected.
+    ///
+    /// This always marks the internal `INIT` bit, so a `CpuInfo` built this way -- including one
+    /// with every `has_*` feature left false -- is never confused with the `0` sentinel
+    /// [`detect_with`]'s cache uses to mean "not yet populated".
     #[inline]
-    fn set(&mut self, bit: u32) {
+    pub fn new() -> Self {
+        let mut info = CpuInfo(0);
+        info.set(CpuInfo::INIT);
+        info
+    }
+
+    /// Marks `bit` (one of the `CpuInfo::HAS_*` constants) as detected.
+    #[inline]
+    pub fn set(&mut self, bit: u32) {
         self.0 = set(self.0, bit);
     }
+    /// Returns whether `bit` (one of the `CpuInfo::HAS_*` constants) was marked as detected.
     #[inline]
-    fn test(self, bit: u32) -> bool {
+    pub fn test(self, bit: u32) -> bool {
         test(self.0, bit)
     }
 }
 
+impl Default for CpuInfo {
+    #[inline]
+    fn default() -> Self {
+        CpuInfo::new()
+    }
+}
+
 #[inline]
 fn set(x: u32, bit: u32) -> u32 {
     x | 1 << bit
@@ -23,22 +48,63 @@ fn test(x: u32, bit: u32) -> bool {
     x & (1 << bit) != 0
 }
 
+/// A pluggable source of [`CpuInfo`], so callers can override run-time feature detection.
+///
+/// The default backend ([`DefaultBackend`]) runs the architecture's real detection logic
+/// (CPUID/HWCAP/system-register reads, depending on target). Implementing this trait lets a
+/// caller go through [`detect_with`] instead of [`detect`] to supply a precomputed `CpuInfo` --
+/// e.g. from a host-provided capability string, from emulator configuration, or to drive a
+/// specific code path (`has_lse == false`, `has_cmpxchg16b == false`, etc.) from a fuzz/test
+/// harness -- without recompiling with arch-specific cfgs.
+///
+/// Note: this module (`detect`) and this trait are only reachable from outside this crate if
+/// some ancestor module re-exports them as `pub`; that re-export lives in `imp`/crate-root glue
+/// that is not part of this file. What this trait and [`detect_with`] guarantee on their own is
+/// the seam itself: a `CpuInfo` is constructible (via [`CpuInfo::new`]) and settable (via
+/// [`CpuInfo::set`]) without needing any of this crate's private detection internals.
+pub trait DetectBackend {
+    /// Performs (or fabricates) detection and returns the resulting [`CpuInfo`].
+    fn detect(&self) -> CpuInfo;
+}
+
+/// The backend [`detect`] uses: the architecture's real detection logic, or, behind
+/// `portable_atomic_test_outline_atomics_detect_false`, every bit forced off.
+struct DefaultBackend;
+
+impl DetectBackend for DefaultBackend {
+    #[inline]
+    fn detect(&self) -> CpuInfo {
+        let mut info = CpuInfo::new();
+        // Note: detect_false cfg is intended to make it easy for portable-atomic developers to
+        // test cases such as has_cmpxchg16b == false, has_lse == false,
+        // __kuser_helper_version < 5, etc., and is not a public API.
+        if !cfg!(portable_atomic_test_outline_atomics_detect_false) {
+            _detect(&mut info);
+        }
+        info
+    }
+}
+
 #[inline]
 pub(crate) fn detect() -> CpuInfo {
+    detect_with(&DefaultBackend)
+}
+
+/// Like [`detect`], but detects through `backend` instead of [`DefaultBackend`].
+///
+/// Still short-circuits on the same process-wide cache `detect()` populates: once any backend
+/// has produced a non-zero `CpuInfo`, later calls (through either function) return it without
+/// detecting again.
+#[inline]
+pub fn detect_with(backend: &impl DetectBackend) -> CpuInfo {
     use core::sync::atomic::{AtomicU32, Ordering};
 
     static CACHE: AtomicU32 = AtomicU32::new(0);
-    let mut info = CpuInfo(CACHE.load(Ordering::Relaxed));
-    if info.0 != 0 {
-        return info;
-    }
-    info.set(CpuInfo::INIT);
-    // Note: detect_false cfg is intended to make it easy for portable-atomic developers to
-    // test cases such as has_cmpxchg16b == false, has_lse == false,
-    // __kuser_helper_version < 5, etc., and is not a public API.
-    if !cfg!(portable_atomic_test_outline_atomics_detect_false) {
-        _detect(&mut info);
+    let cached = CACHE.load(Ordering::Relaxed);
+    if cached != 0 {
+        return CpuInfo(cached);
     }
+    let info = backend.detect();
     CACHE.store(info.0, Ordering::Relaxed);
     info
 }
@@ -46,44 +112,71 @@ pub(crate) fn detect() -> CpuInfo {
 #[cfg(target_arch = "aarch64")]
 impl CpuInfo {
     /// Whether FEAT_LSE is available
-    const HAS_LSE: u32 = 1;
+    pub const HAS_LSE: u32 = 1;
     /// Whether FEAT_LSE2 is available
-    // This is currently only used in tests.
-    #[cfg(test)]
-    const HAS_LSE2: u32 = 2;
+    pub const HAS_LSE2: u32 = 2;
     /// Whether FEAT_LSE128 is available
-    // This is currently only used in tests.
-    #[cfg(test)]
-    const HAS_LSE128: u32 = 3;
+    pub const HAS_LSE128: u32 = 3;
     /// Whether FEAT_LRCPC3 is available
-    // This is currently only used in tests.
-    #[cfg(test)]
-    const HAS_RCPC3: u32 = 4;
+    pub const HAS_RCPC3: u32 = 4;
 
     #[cfg(any(test, not(any(target_feature = "lse", portable_atomic_target_feature = "lse"))))]
     #[inline]
-    pub(crate) fn has_lse(self) -> bool {
+    pub fn has_lse(self) -> bool {
         self.test(CpuInfo::HAS_LSE)
     }
+    #[cfg(any(
+        test,
+        not(any(target_feature = "lse2", portable_atomic_target_feature = "lse2")),
+    ))]
+    #[inline]
+    pub fn has_lse2(self) -> bool {
+        self.test(CpuInfo::HAS_LSE2)
+    }
+    #[cfg(any(
+        test,
+        not(any(target_feature = "lse128", portable_atomic_target_feature = "lse128")),
+    ))]
+    #[inline]
+    pub fn has_lse128(self) -> bool {
+        self.test(CpuInfo::HAS_LSE128)
+    }
+    #[cfg(any(
+        test,
+        not(any(target_feature = "rcpc3", portable_atomic_target_feature = "rcpc3")),
+    ))]
+    #[inline]
+    pub fn has_rcpc3(self) -> bool {
+        self.test(CpuInfo::HAS_RCPC3)
+    }
 }
 
 #[cfg(target_arch = "x86_64")]
 impl CpuInfo {
     /// Whether CMPXCHG16B is available
-    const HAS_CMPXCHG16B: u32 = 1;
-    /// Whether VMOVDQA is atomic
-    const HAS_VMOVDQA_ATOMIC: u32 = 2;
+    pub const HAS_CMPXCHG16B: u32 = 1;
+    /// Whether aligned 128-bit VMOVDQA loads/stores are atomic
+    // Set only when `--cfg portable_atomic_vmovdqa_atomic` is passed and CPUID reports AVX, on
+    // the subset of vendors where aligned VMOVDQA is known to be atomic (Sandy Bridge and later
+    // Intel, and the equivalent AMD generations). This is a vendor/microarchitecture guarantee,
+    // not one given by the x86_64 architecture itself, which is why it needs the opt-in cfg.
+    pub const HAS_VMOVDQA_ATOMIC: u32 = 2;
 
     #[cfg(any(
         test,
         not(any(target_feature = "cmpxchg16b", portable_atomic_target_feature = "cmpxchg16b")),
     ))]
     #[inline]
-    pub(crate) fn has_cmpxchg16b(self) -> bool {
+    pub fn has_cmpxchg16b(self) -> bool {
         self.test(CpuInfo::HAS_CMPXCHG16B)
     }
+    // Whether aligned VMOVDQA loads/stores are atomic is a vendor/microarchitecture guarantee,
+    // not an architectural one, so this is only checked when the user has opted in via
+    // --cfg portable_atomic_vmovdqa_atomic (e.g. because the build targets Sandy Bridge or
+    // later, where the guarantee is known to hold).
+    #[cfg(any(test, portable_atomic_vmovdqa_atomic))]
     #[inline]
-    pub(crate) fn has_vmovdqa_atomic(self) -> bool {
+    pub fn has_vmovdqa_atomic(self) -> bool {
         self.test(CpuInfo::HAS_VMOVDQA_ATOMIC)
     }
 }
@@ -91,7 +184,7 @@ impl CpuInfo {
 #[cfg(target_arch = "powerpc64")]
 impl CpuInfo {
     /// Whether lqarx and stqcx. instructions are available
-    const HAS_QUADWORD_ATOMICS: u32 = 1;
+    pub const HAS_QUADWORD_ATOMICS: u32 = 1;
 
     #[cfg(any(
         test,
@@ -101,11 +194,26 @@ impl CpuInfo {
         )),
     ))]
     #[inline]
-    pub(crate) fn has_quadword_atomics(self) -> bool {
+    pub fn has_quadword_atomics(self) -> bool {
         self.test(CpuInfo::HAS_QUADWORD_ATOMICS)
     }
 }
 
+#[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+impl CpuInfo {
+    /// Whether Zacas is available
+    pub const HAS_ZACAS: u32 = 1;
+
+    #[cfg(any(
+        test,
+        not(any(target_feature = "zacas", portable_atomic_target_feature = "zacas")),
+    ))]
+    #[inline]
+    pub fn has_zacas(self) -> bool {
+        self.test(CpuInfo::HAS_ZACAS)
+    }
+}
+
 // core::ffi::c_* (except c_void) requires Rust 1.64, libc will soon require Rust 1.47
 #[cfg(any(target_arch = "aarch64", target_arch = "powerpc64"))]
 #[cfg(not(windows))]
@@ -236,6 +344,17 @@ mod tests_common {
             assert!(x.test(CpuInfo::INIT));
             assert!(x.test(CpuInfo::HAS_QUADWORD_ATOMICS));
         }
+        #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+        {
+            assert!(!x.test(CpuInfo::INIT));
+            assert!(!x.test(CpuInfo::HAS_ZACAS));
+            x.set(CpuInfo::INIT);
+            assert!(x.test(CpuInfo::INIT));
+            assert!(!x.test(CpuInfo::HAS_ZACAS));
+            x.set(CpuInfo::HAS_ZACAS);
+            assert!(x.test(CpuInfo::INIT));
+            assert!(x.test(CpuInfo::HAS_ZACAS));
+        }
     }
 
     #[test]
@@ -264,6 +383,14 @@ mod tests_common {
                 "lse2",
                 cfg!(any(target_feature = "lse2", portable_atomic_target_feature = "lse2")),
             );
+            print_feature!(
+                "lse128",
+                cfg!(any(target_feature = "lse128", portable_atomic_target_feature = "lse128")),
+            );
+            print_feature!(
+                "rcpc3",
+                cfg!(any(target_feature = "rcpc3", portable_atomic_target_feature = "rcpc3")),
+            );
         }
         #[cfg(target_arch = "x86_64")]
         {
@@ -278,6 +405,7 @@ mod tests_common {
                     portable_atomic_target_feature = "cmpxchg16b",
                 )),
             );
+            print_feature!("vmovdqa-atomic", cfg!(portable_atomic_vmovdqa_atomic));
         }
         #[cfg(target_arch = "powerpc64")]
         {
@@ -292,6 +420,16 @@ mod tests_common {
                 )),
             );
         }
+        #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+        {
+            features.push_str("run-time:\n");
+            print_feature!("zacas", detect().test(CpuInfo::HAS_ZACAS));
+            features.push_str("compile-time:\n");
+            print_feature!(
+                "zacas",
+                cfg!(any(target_feature = "zacas", portable_atomic_target_feature = "zacas")),
+            );
+        }
         let stdout = std::io::stderr();
         let mut stdout = stdout.lock();
         let _ = stdout.write_all(features.as_bytes());
@@ -308,6 +446,7 @@ mod tests_common {
         }
         if detect().has_vmovdqa_atomic() {
             assert!(detect().test(CpuInfo::HAS_VMOVDQA_ATOMIC));
+            assert!(std::is_x86_feature_detected!("avx"));
         } else {
             assert!(!detect().test(CpuInfo::HAS_VMOVDQA_ATOMIC));
         }
@@ -328,7 +467,7 @@ mod tests_common {
                 assert!(!proc_cpuinfo.lse);
             }
         }
-        if detect().test(CpuInfo::HAS_LSE2) {
+        if detect().has_lse2() {
             assert!(detect().test(CpuInfo::HAS_LSE));
             assert!(detect().test(CpuInfo::HAS_LSE2));
             if let Ok(test_helper::cpuinfo::ProcCpuinfo { lse2: Some(lse2), .. }) = proc_cpuinfo {
@@ -340,14 +479,14 @@ mod tests_common {
                 assert!(!lse2);
             }
         }
-        if detect().test(CpuInfo::HAS_LSE128) {
+        if detect().has_lse128() {
             assert!(detect().test(CpuInfo::HAS_LSE));
             assert!(detect().test(CpuInfo::HAS_LSE2));
             assert!(detect().test(CpuInfo::HAS_LSE128));
         } else {
             assert!(!detect().test(CpuInfo::HAS_LSE128));
         }
-        if detect().test(CpuInfo::HAS_RCPC3) {
+        if detect().has_rcpc3() {
             assert!(detect().test(CpuInfo::HAS_RCPC3));
         } else {
             assert!(!detect().test(CpuInfo::HAS_RCPC3));
@@ -370,4 +509,14 @@ mod tests_common {
             }
         }
     }
+    #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+    #[test]
+    #[cfg_attr(portable_atomic_test_outline_atomics_detect_false, ignore)]
+    fn test_detect() {
+        if detect().has_zacas() {
+            assert!(detect().test(CpuInfo::HAS_ZACAS));
+        } else {
+            assert!(!detect().test(CpuInfo::HAS_ZACAS));
+        }
+    }
 }
@@ -0,0 +1,20 @@
+// Run-time CPU feature detection, used by the 128-bit atomic intrinsics to decide between the
+// native instruction sequence and an outlined fallback.
+//
+// Each `#[path]` module below supplies the architecture's real `_detect` function and then pulls
+// in the shared `CpuInfo`/`DetectBackend`/`detect`/`detect_with` scaffolding via
+// `include!("common.rs")`, so there is exactly one active copy of that scaffolding per target.
+
+#[cfg(target_arch = "aarch64")]
+#[path = "aarch64.rs"]
+mod arch;
+
+#[cfg(target_arch = "x86_64")]
+#[path = "x86_64.rs"]
+mod arch;
+
+#[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+#[path = "riscv64.rs"]
+mod arch;
+
+pub(crate) use arch::{detect, detect_with, CpuInfo, DetectBackend};
@@ -0,0 +1,64 @@
+// RISC-V (riscv64 and riscv32) run-time feature detection via the `riscv_hwprobe` syscall
+// (Linux 6.4+).
+//
+// Refs:
+// - https://docs.kernel.org/arch/riscv/hwprobe.html
+// - https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/hwprobe.h
+
+include!("common.rs");
+
+#[cfg(target_os = "linux")]
+mod os {
+    use super::CpuInfo;
+
+    // sys/riscv_hwprobe.h: struct riscv_hwprobe { long long key; unsigned long long value; }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct RiscvHwprobe {
+        key: i64,
+        value: u64,
+    }
+
+    const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+    const RISCV_HWPROBE_EXT_ZACAS: u64 = 1 << 34;
+
+    // arch/riscv/include/uapi/asm/unistd.h
+    const SYS_RISCV_HWPROBE: core::ffi::c_long = 258;
+
+    extern "C" {
+        fn syscall(number: core::ffi::c_long, ...) -> core::ffi::c_long;
+    }
+
+    pub(super) fn detect(info: &mut CpuInfo) {
+        let mut pairs = [RiscvHwprobe { key: RISCV_HWPROBE_KEY_IMA_EXT_0, value: 0 }];
+        // SAFETY: `pairs` is a valid array of `riscv_hwprobe` structs matching the syscall's
+        // (pairs, pair_count, cpu_count=0, cpus=null, flags=0) calling convention.
+        let res = unsafe {
+            syscall(
+                SYS_RISCV_HWPROBE,
+                pairs.as_mut_ptr(),
+                pairs.len(),
+                0usize,
+                core::ptr::null_mut::<core::ffi::c_void>(),
+                0u32,
+            )
+        };
+        if res == 0
+            && pairs[0].key == RISCV_HWPROBE_KEY_IMA_EXT_0
+            && pairs[0].value & RISCV_HWPROBE_EXT_ZACAS != 0
+        {
+            info.set(CpuInfo::HAS_ZACAS);
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod os {
+    use super::CpuInfo;
+
+    pub(super) fn detect(_info: &mut CpuInfo) {}
+}
+
+fn _detect(info: &mut CpuInfo) {
+    os::detect(info);
+}
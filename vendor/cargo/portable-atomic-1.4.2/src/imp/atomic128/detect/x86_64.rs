@@ -0,0 +1,36 @@
+// x86_64 run-time feature detection via CPUID.
+//
+// Refs:
+// - Intel 64 and IA-32 Architectures Software Developer's Manual, Volume 2A: CPUID.
+
+include!("common.rs");
+
+// CPUID.1:ECX
+const ECX_CMPXCHG16B: u32 = 1 << 13;
+const ECX_AVX: u32 = 1 << 28;
+
+// CPUID.0 vendor string, as (EBX, EDX, ECX).
+const VENDOR_INTEL: (u32, u32, u32) = (0x756e_6547, 0x4965_6e69, 0x6c65_746e); // "GenuineIntel"
+const VENDOR_AMD: (u32, u32, u32) = (0x6874_7541, 0x6974_6e65, 0x444d_4163); // "AuthenticAMD"
+
+fn _detect(info: &mut CpuInfo) {
+    // SAFETY: CPUID leaves 0 and 1 are available on all x86_64 CPUs.
+    let cpuid0 = unsafe { core::arch::x86_64::__cpuid(0) };
+    // SAFETY: same as above.
+    let cpuid1 = unsafe { core::arch::x86_64::__cpuid(1) };
+    let vendor = (cpuid0.ebx, cpuid0.edx, cpuid0.ecx);
+
+    if cpuid1.ecx & ECX_CMPXCHG16B != 0 {
+        info.set(CpuInfo::HAS_CMPXCHG16B);
+    }
+    // Aligned VMOVDQA loads/stores are only known to be atomic on a subset of the vendors/
+    // microarchitectures that report AVX, so this is gated on the opt-in
+    // `portable_atomic_vmovdqa_atomic` cfg and on an Intel or AMD vendor string (see the
+    // comment on `HAS_VMOVDQA_ATOMIC` in common.rs).
+    if cfg!(portable_atomic_vmovdqa_atomic)
+        && (vendor == VENDOR_INTEL || vendor == VENDOR_AMD)
+        && cpuid1.ecx & ECX_AVX != 0
+    {
+        info.set(CpuInfo::HAS_VMOVDQA_ATOMIC);
+    }
+}
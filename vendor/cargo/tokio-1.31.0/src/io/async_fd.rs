@@ -1,12 +1,36 @@
-use crate::io::{Interest, Ready};
+use crate::io::{AsyncRead, AsyncWrite, Interest, ReadBuf, Ready};
 use crate::runtime::io::{ReadyEvent, Registration};
 use crate::runtime::scheduler;
 
 use mio::unix::SourceFd;
-use std::io;
-use std::os::unix::io::{AsRawFd, RawFd};
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsRawFd, OwnedFd, RawFd};
+use std::pin::Pin;
 use std::{task::Context, task::Poll};
 
+/// Rejects an `interest` that names more than one direction.
+///
+/// [`AsyncFd::async_io`]/[`AsyncFd::async_io_mut`] clear readiness on `WouldBlock` based only on
+/// an opaque `io::Error`, with no way for the closure to say which direction of a combined
+/// interest actually blocked; guessing would risk clearing a still-ready direction's
+/// edge-triggered notification before it's acted on. So those two methods require a single
+/// direction and report this as an error instead of silently misbehaving.
+fn require_single_direction(interest: Interest) -> io::Result<()> {
+    let directions = u8::from(interest.is_readable())
+        + u8::from(interest.is_writable())
+        + u8::from(interest.is_priority());
+    if directions <= 1 {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "async_io/async_io_mut do not support a combined `Interest`; call them once per \
+             direction, or drive a combined interest manually with \
+             AsyncFdReadyGuard::try_io_matching",
+        ))
+    }
+}
+
 /// Associates an IO object backed by a Unix file descriptor with the tokio
 /// reactor, allowing for readiness to be polled. The file descriptor must be of
 /// a type that can be used with the OS polling facilities (ie, `poll`, `epoll`,
@@ -62,6 +86,16 @@ use std::{task::Context, task::Poll};
 /// the limitation that only one task can wait on each direction (read or write)
 /// at a time.
 ///
+/// ## Priority readiness
+///
+/// In addition to [`Interest::READABLE`] and [`Interest::WRITABLE`], some file descriptors
+/// signal events through out-of-band/priority notifications (`EPOLLPRI` on Linux) rather than
+/// ordinary readability. [`AsyncFd::priority_ready`] and [`AsyncFd::priority_ready_mut`] wait on
+/// [`Interest::PRIORITY`] for this case. Not all platforms support priority readiness; on
+/// platforms that don't, registering a priority interest surfaces an [`io::Error`] rather than
+/// silently degrading to ordinary readability.
+///
+
 /// # Examples
 ///
 /// This example shows how to turn [`std::net::TcpStream`] asynchronous using
@@ -176,6 +210,7 @@ use std::{task::Context, task::Poll};
 /// [`AsyncWrite`]: trait@crate::io::AsyncWrite
 pub struct AsyncFd<T: AsRawFd> {
     registration: Registration,
+    interest: Interest,
     inner: Option<T>,
 }
 
@@ -207,7 +242,9 @@ impl<T: AsRawFd> AsyncFd<T> {
     /// time of creation.
     ///
     /// Only configures the [`Interest::READABLE`] and [`Interest::WRITABLE`] interests. For more
-    /// control, use [`AsyncFd::with_interest`].
+    /// control over the initial interest, use [`AsyncFd::with_interest`]; to change the
+    /// interest of an already-registered `AsyncFd` without tearing it down, use
+    /// [`AsyncFd::set_interest`].
     ///
     /// This method must be called in the context of a tokio runtime.
     ///
@@ -241,6 +278,24 @@ impl<T: AsRawFd> AsyncFd<T> {
         Self::new_with_handle_and_interest(inner, scheduler::Handle::current(), interest)
     }
 
+    /// Creates an AsyncFd backed by (and taking ownership of) an [`OwnedFd`], with the default
+    /// [`Interest::READABLE`] and [`Interest::WRITABLE`] interests.
+    ///
+    /// Unlike [`AsyncFd::new`], which only requires [`AsRawFd`] and relies on documented
+    /// invariants to avoid use-after-close/double-register bugs, this constructor uses
+    /// [`OwnedFd`] to let the standard I/O-safety types enforce that the file descriptor is
+    /// uniquely owned and does not change for the lifetime of the registration.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no current reactor set, or if the `rt` feature flag is
+    /// not enabled.
+    #[inline]
+    #[track_caller]
+    pub fn from_owned_fd(fd: OwnedFd) -> io::Result<AsyncFd<OwnedFd>> {
+        AsyncFd::with_interest(fd, Interest::READABLE | Interest::WRITABLE)
+    }
+
     #[track_caller]
     pub(crate) fn new_with_handle_and_interest(
         inner: T,
@@ -254,10 +309,19 @@ impl<T: AsRawFd> AsyncFd<T> {
 
         Ok(AsyncFd {
             registration,
+            interest,
             inner: Some(inner),
         })
     }
 
+    /// Returns a [`BorrowedFd`] borrowing the file descriptor backing this [`AsyncFd`].
+    ///
+    /// [`BorrowedFd`]: std::os::unix::io::BorrowedFd
+    #[inline]
+    pub fn as_fd(&self) -> std::os::unix::io::BorrowedFd<'_> {
+        unsafe { std::os::unix::io::BorrowedFd::borrow_raw(self.as_raw_fd()) }
+    }
+
     /// Returns a shared reference to the backing object of this [`AsyncFd`].
     #[inline]
     pub fn get_ref(&self) -> &T {
@@ -270,6 +334,35 @@ impl<T: AsRawFd> AsyncFd<T> {
         self.inner.as_mut().unwrap()
     }
 
+    /// Returns the [`Interest`] this [`AsyncFd`] is currently registered with.
+    #[inline]
+    pub fn interest(&self) -> Interest {
+        self.interest
+    }
+
+    /// Changes the [`Interest`] this [`AsyncFd`] is registered with.
+    ///
+    /// This deregisters the backing file descriptor and registers it again with the new
+    /// [`Interest`], same as tearing the [`AsyncFd`] down with [`into_inner`] and rebuilding it
+    /// with [`with_interest`] would, except that the [`AsyncFd`] itself (and its backing object)
+    /// stays put. Because the registration is torn down and recreated, any readiness already
+    /// cached by the reactor is discarded, even for interests that remain requested after the
+    /// change; the next [`ready`](Self::ready)/poll call will re-observe readiness from scratch.
+    ///
+    /// [`into_inner`]: AsyncFd::into_inner
+    /// [`with_interest`]: AsyncFd::with_interest
+    pub fn set_interest(&mut self, interest: Interest) -> io::Result<()> {
+        let fd = self.get_ref().as_raw_fd();
+        let _ = self.registration.deregister(&mut SourceFd(&fd));
+        self.registration = Registration::new_with_interest_and_handle(
+            &mut SourceFd(&fd),
+            interest,
+            scheduler::Handle::current(),
+        )?;
+        self.interest = interest;
+        Ok(())
+    }
+
     fn take_inner(&mut self) -> Option<T> {
         let fd = self.inner.as_ref().map(AsRawFd::as_raw_fd);
 
@@ -442,6 +535,55 @@ impl<T: AsRawFd> AsyncFd<T> {
         .into()
     }
 
+    /// Polls for any of the requested ready states.
+    ///
+    /// This is the poll-based counterpart to [`ready`](Self::ready): it is intended for cases
+    /// where creating and pinning a future is not feasible, such as implementing a custom
+    /// [`Future`], [`Stream`], or `AsyncRead`/`AsyncWrite` adapter by hand on top of a raw file
+    /// descriptor (for example a tun/tap device).
+    ///
+    /// This method takes `&self`, so it is possible to call this method concurrently with other
+    /// methods on this struct. This method only provides shared access to the inner IO resource
+    /// when handling the [`AsyncFdReadyGuard`].
+    ///
+    /// [`Future`]: std::future::Future
+    /// [`Stream`]: crate::stream::Stream
+    // Alias kept for callers bridging hand-written `poll_*`-based state machines straight to a
+    // guard, without going through an intermediate future.
+    #[cfg_attr(docsrs, doc(alias = "poll_io_ready"))]
+    pub fn poll_ready<'a>(
+        &'a self,
+        cx: &mut Context<'_>,
+        interest: Interest,
+    ) -> Poll<io::Result<AsyncFdReadyGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_readiness(cx, interest))?;
+
+        Ok(AsyncFdReadyGuard {
+            async_fd: self,
+            event: Some(event),
+        })
+        .into()
+    }
+
+    /// Polls for any of the requested ready states.
+    ///
+    /// The behavior is the same as [`poll_ready`](Self::poll_ready), except that this method
+    /// takes `&mut self`, so it is possible to access the inner IO resource mutably when
+    /// handling the [`AsyncFdReadyMutGuard`].
+    pub fn poll_ready_mut<'a>(
+        &'a mut self,
+        cx: &mut Context<'_>,
+        interest: Interest,
+    ) -> Poll<io::Result<AsyncFdReadyMutGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_readiness(cx, interest))?;
+
+        Ok(AsyncFdReadyMutGuard {
+            async_fd: self,
+            event: Some(event),
+        })
+        .into()
+    }
+
     /// Waits for any of the requested ready states, returning a
     /// [`AsyncFdReadyGuard`] that must be dropped to resume
     /// polling for the requested ready states.
@@ -687,6 +829,33 @@ impl<T: AsRawFd> AsyncFd<T> {
         self.ready_mut(Interest::WRITABLE).await
     }
 
+    /// Waits for the file descriptor to receive out-of-band/priority data (`EPOLLPRI` on
+    /// Linux), returning a [`AsyncFdReadyGuard`] that must be dropped to resume
+    /// priority-readiness polling.
+    ///
+    /// This is useful for file descriptors that signal events through `EPOLLPRI` rather than
+    /// ordinary readability, such as TCP sockets carrying urgent data, or
+    /// `/sys/class/gpio/.../value` files used for edge-triggered GPIO interrupts.
+    ///
+    /// This method takes `&self`, so it is possible to call this method concurrently with
+    /// other methods on this struct. This method only provides shared access to the inner IO
+    /// resource when handling the [`AsyncFdReadyGuard`].
+    #[allow(clippy::needless_lifetimes)] // The lifetime improves rustdoc rendering.
+    pub async fn priority_ready<'a>(&'a self) -> io::Result<AsyncFdReadyGuard<'a, T>> {
+        self.ready(Interest::PRIORITY).await
+    }
+
+    /// Waits for the file descriptor to receive out-of-band/priority data (`EPOLLPRI` on
+    /// Linux), returning a [`AsyncFdReadyMutGuard`] that must be dropped to resume
+    /// priority-readiness polling.
+    ///
+    /// This method takes `&mut self`, so it is possible to access the inner IO resource
+    /// mutably when handling the [`AsyncFdReadyMutGuard`].
+    #[allow(clippy::needless_lifetimes)] // The lifetime improves rustdoc rendering.
+    pub async fn priority_ready_mut<'a>(&'a mut self) -> io::Result<AsyncFdReadyMutGuard<'a, T>> {
+        self.ready_mut(Interest::PRIORITY).await
+    }
+
     /// Reads or writes from the file descriptor using a user-provided IO operation.
     ///
     /// The `async_io` method is a convenience utility that waits for the file
@@ -729,10 +898,15 @@ impl<T: AsRawFd> AsyncFd<T> {
     /// defined on the Tokio [`AsyncFd`] type, as this will mess with the
     /// readiness flag and can cause the file descriptor to behave incorrectly.
     ///
-    /// This method is not intended to be used with combined interests.
-    /// The closure should perform only one type of IO operation, so it should not
-    /// require more than one ready state. This method may panic or sleep forever
-    /// if it is called with a combined interest.
+    /// `interest` must name a single direction (e.g. `Interest::READABLE` or
+    /// `Interest::WRITABLE`, not their combination). The closure reports blocking only as an
+    /// opaque [`WouldBlock`] error, with no way to say which direction of a combined interest
+    /// actually blocked; clearing a guessed combination on every [`WouldBlock`] risks wiping out
+    /// a still-ready direction's edge-triggered notification before it's ever acted on. Driving
+    /// both directions from one call therefore isn't supported here -- call `async_io` once per
+    /// direction, or drive a combined interest manually with
+    /// [`AsyncFdReadyGuard::try_io_matching`], which lets the caller say exactly which readiness
+    /// to clear.
     ///
     /// # Examples
     ///
@@ -769,9 +943,16 @@ impl<T: AsRawFd> AsyncFd<T> {
         interest: Interest,
         mut f: impl FnMut(&T) -> io::Result<R>,
     ) -> io::Result<R> {
-        self.registration
-            .async_io(interest, || f(self.get_ref()))
-            .await
+        require_single_direction(interest)?;
+        loop {
+            let mut guard = self.ready(interest).await?;
+            let ready = guard.ready();
+
+            match guard.try_io_matching(ready, |async_fd| f(async_fd.get_ref())) {
+                Ok(result) => return result,
+                Err(_would_block) => continue,
+            }
+        }
     }
 
     /// Reads or writes from the file descriptor using a user-provided IO operation.
@@ -785,9 +966,89 @@ impl<T: AsRawFd> AsyncFd<T> {
         interest: Interest,
         mut f: impl FnMut(&mut T) -> io::Result<R>,
     ) -> io::Result<R> {
-        self.registration
-            .async_io(interest, || f(self.inner.as_mut().unwrap()))
-            .await
+        require_single_direction(interest)?;
+        loop {
+            let mut guard = self.ready_mut(interest).await?;
+            let ready = guard.ready();
+
+            match guard.try_io_matching(ready, |async_fd| f(async_fd.get_mut())) {
+                Ok(result) => return result,
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    /// Reads or writes from the file descriptor using a user-provided IO operation, without
+    /// first waiting for the file descriptor to become ready.
+    ///
+    /// This is the synchronous counterpart to [`async_io`]: it checks the currently cached
+    /// readiness for `interest` and, if it is not set, returns `Err(TryIoError(()))`
+    /// immediately rather than awaiting. If the readiness is set, `f` is invoked, and if it
+    /// returns a [`WouldBlock`] error the cached readiness is cleared, matching the behavior of
+    /// [`AsyncFdReadyGuard::try_io`].
+    ///
+    /// This method is useful when combined with [`poll_read_ready`]/[`poll_write_ready`] is not
+    /// necessary, such as when checking readiness opportunistically before falling back to the
+    /// async path.
+    ///
+    /// Unlike [`AsyncFdReadyGuard::try_io`], this does not require first obtaining a guard via
+    /// [`readable`]/[`writable`]/[`ready`]; the readiness check and the IO attempt happen in a
+    /// single call:
+    ///
+    /// ```no_run
+    /// use tokio::io::{Interest, unix::AsyncFd};
+    ///
+    /// use std::io;
+    /// use std::net::UdpSocket;
+    ///
+    /// fn try_send(async_fd: &AsyncFd<UdpSocket>, buf: &[u8]) -> io::Result<Option<usize>> {
+    ///     match async_fd.try_io(Interest::WRITABLE, |inner| inner.send(buf)) {
+    ///         Ok(result) => result.map(Some),
+    ///         Err(_would_block) => Ok(None),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`async_io`]: AsyncFd::async_io
+    /// [`readable`]: AsyncFd::readable
+    /// [`writable`]: AsyncFd::writable
+    /// [`ready`]: AsyncFd::ready
+    /// [`poll_read_ready`]: AsyncFd::poll_read_ready
+    /// [`poll_write_ready`]: AsyncFd::poll_write_ready
+    /// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_io<R>(
+        &self,
+        interest: Interest,
+        f: impl FnOnce(&T) -> io::Result<R>,
+    ) -> Result<io::Result<R>, TryIoError> {
+        let result = self.registration.try_io(interest, || f(self.get_ref()));
+
+        match result {
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => Err(TryIoError(())),
+            result => Ok(result),
+        }
+    }
+
+    /// Reads or writes from the file descriptor using a user-provided IO operation, without
+    /// first waiting for the file descriptor to become ready.
+    ///
+    /// The behavior is the same as [`try_io`], except that the closure can mutate the inner
+    /// value of the [`AsyncFd`].
+    ///
+    /// [`try_io`]: AsyncFd::try_io
+    pub fn try_io_mut<R>(
+        &mut self,
+        interest: Interest,
+        f: impl FnOnce(&mut T) -> io::Result<R>,
+    ) -> Result<io::Result<R>, TryIoError> {
+        let result = self
+            .registration
+            .try_io(interest, || f(self.inner.as_mut().unwrap()));
+
+        match result {
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => Err(TryIoError(())),
+            result => Ok(result),
+        }
     }
 }
 
@@ -799,7 +1060,7 @@ impl<T: AsRawFd> AsRawFd for AsyncFd<T> {
 
 impl<T: AsRawFd> std::os::unix::io::AsFd for AsyncFd<T> {
     fn as_fd(&self) -> std::os::unix::io::BorrowedFd<'_> {
-        unsafe { std::os::unix::io::BorrowedFd::borrow_raw(self.as_raw_fd()) }
+        AsyncFd::as_fd(self)
     }
 }
 
@@ -1010,12 +1271,29 @@ impl<'a, Inner: AsRawFd> AsyncFdReadyGuard<'a, Inner> {
     pub fn try_io<R>(
         &mut self,
         f: impl FnOnce(&'a AsyncFd<Inner>) -> io::Result<R>,
+    ) -> Result<io::Result<R>, TryIoError> {
+        self.try_io_matching(self.ready(), f)
+    }
+
+    /// Performs the provided IO operation, explicitly clearing only the given `ready` bits on
+    /// [`WouldBlock`] instead of clearing everything this guard was created to observe.
+    ///
+    /// This is useful when the guard was obtained from a combined interest (e.g.
+    /// `Interest::READABLE | Interest::WRITABLE`) and the caller knows precisely which
+    /// direction blocked, letting the still-ready direction's edge-triggered notification
+    /// survive instead of being wiped alongside it.
+    ///
+    /// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_io_matching<R>(
+        &mut self,
+        ready: Ready,
+        f: impl FnOnce(&'a AsyncFd<Inner>) -> io::Result<R>,
     ) -> Result<io::Result<R>, TryIoError> {
         let result = f(self.async_fd);
 
         if let Err(e) = result.as_ref() {
             if e.kind() == io::ErrorKind::WouldBlock {
-                self.clear_ready();
+                self.clear_ready_matching(ready);
             }
         }
 
@@ -1025,6 +1303,41 @@ impl<'a, Inner: AsRawFd> AsyncFdReadyGuard<'a, Inner> {
         }
     }
 
+    /// Performs a partial-completion-aware IO operation, such as a vectored `writev`/`readv`
+    /// that may transfer fewer than `total_len` bytes before blocking.
+    ///
+    /// Unlike [`try_io`], which treats any `Ok` result as fully clearing the need to retry, this
+    /// variant understands that an `Ok(n)` with `n < total_len` means the fd may still be ready
+    /// (readiness is *not* cleared in that case), while only a genuine [`WouldBlock`] error
+    /// clears the cached readiness. This lets `AsyncRead`/`AsyncWrite` adapters built on top of
+    /// `AsyncFd` implement `poll_read_vectored`/`poll_write_vectored` correctly under
+    /// edge-triggered readiness: a short write isn't mistaken for the fd no longer being
+    /// writable.
+    ///
+    /// [`try_io`]: AsyncFdReadyGuard::try_io
+    /// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_io_partial(
+        &mut self,
+        total_len: usize,
+        f: impl FnOnce(&'a AsyncFd<Inner>) -> io::Result<usize>,
+    ) -> Result<io::Result<usize>, TryIoError> {
+        let result = f(self.async_fd);
+
+        match &result {
+            Ok(n) if *n < total_len => {
+                // A short transfer isn't a WouldBlock: the fd may still be ready, so leave the
+                // cached readiness alone instead of clearing it.
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => self.clear_ready(),
+            _ => {}
+        }
+
+        match result {
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => Err(TryIoError(())),
+            result => Ok(result),
+        }
+    }
+
     /// Returns a shared reference to the inner [`AsyncFd`].
     pub fn get_ref(&self) -> &'a AsyncFd<Inner> {
         self.async_fd
@@ -1190,12 +1503,29 @@ impl<'a, Inner: AsRawFd> AsyncFdReadyMutGuard<'a, Inner> {
     pub fn try_io<R>(
         &mut self,
         f: impl FnOnce(&mut AsyncFd<Inner>) -> io::Result<R>,
+    ) -> Result<io::Result<R>, TryIoError> {
+        self.try_io_matching(self.ready(), f)
+    }
+
+    /// Performs the provided IO operation, explicitly clearing only the given `ready` bits on
+    /// [`WouldBlock`] instead of clearing everything this guard was created to observe.
+    ///
+    /// This is useful when the guard was obtained from a combined interest (e.g.
+    /// `Interest::READABLE | Interest::WRITABLE`) and the caller knows precisely which
+    /// direction blocked, letting the still-ready direction's edge-triggered notification
+    /// survive instead of being wiped alongside it.
+    ///
+    /// [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+    pub fn try_io_matching<R>(
+        &mut self,
+        ready: Ready,
+        f: impl FnOnce(&mut AsyncFd<Inner>) -> io::Result<R>,
     ) -> Result<io::Result<R>, TryIoError> {
         let result = f(self.async_fd);
 
         if let Err(e) = result.as_ref() {
             if e.kind() == io::ErrorKind::WouldBlock {
-                self.clear_ready();
+                self.clear_ready_matching(ready);
             }
         }
 
@@ -1205,6 +1535,30 @@ impl<'a, Inner: AsRawFd> AsyncFdReadyMutGuard<'a, Inner> {
         }
     }
 
+    /// Performs a partial-completion-aware IO operation, such as a vectored `writev`/`readv`
+    /// that may transfer fewer than `total_len` bytes before blocking. See
+    /// [`AsyncFdReadyGuard::try_io_partial`] for the full semantics.
+    ///
+    /// [`AsyncFdReadyGuard::try_io_partial`]: AsyncFdReadyGuard::try_io_partial
+    pub fn try_io_partial(
+        &mut self,
+        total_len: usize,
+        f: impl FnOnce(&mut AsyncFd<Inner>) -> io::Result<usize>,
+    ) -> Result<io::Result<usize>, TryIoError> {
+        let result = f(self.async_fd);
+
+        match &result {
+            Ok(n) if *n < total_len => {}
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => self.clear_ready(),
+            _ => {}
+        }
+
+        match result {
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => Err(TryIoError(())),
+            result => Ok(result),
+        }
+    }
+
     /// Returns a shared reference to the inner [`AsyncFd`].
     pub fn get_ref(&self) -> &AsyncFd<Inner> {
         self.async_fd
@@ -1242,6 +1596,79 @@ impl<'a, T: std::fmt::Debug + AsRawFd> std::fmt::Debug for AsyncFdReadyMutGuard<
     }
 }
 
+impl<T: Read + AsRawFd> AsyncRead for AsyncFd<T> {
+    /// Attempts to read from the file descriptor, retrying whenever the previous attempt was
+    /// reported ready but actually blocked.
+    ///
+    /// This makes any [`Read`]-implementing file descriptor wrapped in an [`AsyncFd`] (serial
+    /// ports, char devices, custom sockets, ...) usable with [`crate::io::copy`],
+    /// [`crate::io::BufReader`], and codecs, without bespoke glue per fd type.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = ready!(self.poll_read_ready(cx))?;
+
+            let unfilled = buf.initialize_unfilled();
+            match guard.try_io(|inner| inner.get_ref().read(unfilled)) {
+                Ok(Ok(len)) => {
+                    buf.advance(len);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(err)) => return Poll::Ready(Err(err)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl<T: Write + AsRawFd> AsyncWrite for AsyncFd<T> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = ready!(self.poll_write_ready(cx))?;
+
+            match guard.try_io(|inner| inner.get_ref().write(buf)) {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    /// This is a no-op, as there is no way to flush a generic file descriptor.
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    /// Shuts down the write half of the underlying file descriptor via POSIX `shutdown(2)`,
+    /// if it is a socket.
+    ///
+    /// `T` is not always a socket -- this blanket impl also covers serial ports, char devices,
+    /// and other `Read + Write + AsRawFd` types that have no write half to shut down. For those,
+    /// `shutdown(2)` simply fails (`ENOTSOCK`), and this stays a no-op rather than surfacing that
+    /// failure as an error.
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let fd = self.get_ref().as_raw_fd();
+        // SAFETY: `fd` is the raw file descriptor this `AsyncFd` owns and keeps alive for the
+        // duration of this call. Calling `shutdown` on a non-socket fd is not undefined
+        // behavior, it just fails, which is treated as a no-op below.
+        let _ = unsafe { shutdown(fd, SHUT_WR) };
+        Poll::Ready(Ok(()))
+    }
+}
+
+// POSIX shutdown(2). SHUT_WR is 1 on every Unix target.
+const SHUT_WR: std::os::raw::c_int = 1;
+
+extern "C" {
+    fn shutdown(fd: std::os::raw::c_int, how: std::os::raw::c_int) -> std::os::raw::c_int;
+}
+
 /// The error type returned by [`try_io`].
 ///
 /// This error indicates that the IO resource returned a [`WouldBlock`] error.
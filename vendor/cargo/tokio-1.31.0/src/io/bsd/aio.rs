@@ -0,0 +1,155 @@
+use crate::io::Interest;
+use crate::runtime::io::{ReadyEvent, Registration};
+use crate::runtime::scheduler;
+
+use mio::event::Source;
+use mio::{Registry, Token};
+use std::io;
+use std::task::{Context, Poll};
+
+/// Associates a type implementing [`AioSource`] with the tokio reactor, allowing kqueue's
+/// `EVFILT_AIO` completion notifications for a single in-flight POSIX async I/O request
+/// (`aio_read`/`aio_write`/`aio_fsync`) to be awaited.
+///
+/// Unlike [`AsyncFd`](super::super::unix::AsyncFd), which polls a file descriptor for
+/// level/edge-triggered readability or writability, `Aio` tracks completion of a single
+/// outstanding `aiocb`. Only one operation may be in flight for a given `Aio` at a time: start
+/// the operation (e.g. call `aio_read`) before constructing the `Aio`, or between completions,
+/// and await [`Aio::ready`] to learn when the kernel has posted the completion event.
+///
+/// The `aiocb` backing the source must remain at a stable address for as long as it is
+/// registered, which is why `T` is required to keep its control block behind a stable
+/// allocation (or otherwise pin it): `Aio` stores `T` inline, so `T` itself moves into the
+/// `Aio` after [`Aio::new`] registers it, and again whenever the `Aio` is moved.
+///
+/// This type is only available on BSD-family targets (including macOS) where kqueue supports
+/// `EVFILT_AIO`.
+pub struct Aio<T: AioSource> {
+    registration: Registration,
+    io: Option<T>,
+}
+
+/// A type that can be registered for `EVFILT_AIO` completion notifications.
+///
+/// Implementors register (and deregister) the `aiocb` (or equivalent POSIX AIO control block)
+/// that was submitted to the kernel with kqueue themselves; `Aio` calls these methods with the
+/// reactor's `Registry` and token so that it is woken on the request's completion.
+pub trait AioSource {
+    /// Registers `self` for `EVFILT_AIO` completion notification with the given kqueue
+    /// `Registry`, under `token`.
+    fn register(&mut self, registry: &Registry, token: Token);
+    /// Deregisters `self` from the given kqueue `Registry`.
+    fn deregister(&mut self, registry: &Registry);
+}
+
+/// Adapts an [`AioSource`] to a [`mio::event::Source`] so it can be handed to
+/// [`Registration::new_with_interest_and_handle`], mirroring the role [`SourceFd`]
+/// (`mio::unix::SourceFd`) plays for [`AsyncFd`](super::super::unix::AsyncFd).
+struct SourceAio<'a, T: AioSource>(&'a mut T);
+
+impl<'a, T: AioSource> Source for SourceAio<'a, T> {
+    fn register(&mut self, registry: &Registry, token: Token, _: mio::Interest) -> io::Result<()> {
+        self.0.register(registry, token);
+        Ok(())
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        _: mio::Interest,
+    ) -> io::Result<()> {
+        self.0.deregister(registry);
+        self.0.register(registry, token);
+        Ok(())
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        self.0.deregister(registry);
+        Ok(())
+    }
+}
+
+/// An IO-ready event for an in-flight AIO operation. Like
+/// [`AsyncFdReadyGuard`](super::super::unix::AsyncFdReadyGuard), this must be used to
+/// acknowledge (or intentionally decline to acknowledge) completion.
+#[must_use = "You must explicitly choose whether to clear the readiness state by calling a method on AioEvent"]
+pub struct AioEvent<'a, T: AioSource> {
+    aio: &'a Aio<T>,
+    event: Option<ReadyEvent>,
+}
+
+impl<T: AioSource> Aio<T> {
+    /// Creates an `Aio` backed by (and taking ownership of) a value implementing [`AioSource`].
+    /// The caller is expected to have already submitted the underlying `aiocb` to the kernel
+    /// (e.g. via `aio_read`) before awaiting [`Aio::ready`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no current reactor set, or if the `rt` feature flag is
+    /// not enabled.
+    #[track_caller]
+    pub fn new(mut io: T) -> io::Result<Self> {
+        let handle = scheduler::Handle::current();
+        let registration = Registration::new_with_interest_and_handle(
+            &mut SourceAio(&mut io),
+            Interest::READABLE,
+            handle,
+        )?;
+
+        Ok(Self {
+            registration,
+            io: Some(io),
+        })
+    }
+
+    /// Returns a shared reference to the backing [`AioSource`].
+    pub fn get_ref(&self) -> &T {
+        self.io.as_ref().unwrap()
+    }
+
+    /// Returns a mutable reference to the backing [`AioSource`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.io.as_mut().unwrap()
+    }
+
+    /// Polls for the AIO completion event.
+    ///
+    /// A spurious wakeup with no completion posted by the kernel surfaces as `Poll::Pending`
+    /// rather than an error, matching the behavior of [`AsyncFd::poll_read_ready`].
+    ///
+    /// [`AsyncFd::poll_read_ready`]: super::super::unix::AsyncFd::poll_read_ready
+    pub fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<io::Result<AioEvent<'_, T>>> {
+        let event = ready!(self.registration.poll_read_ready(cx))?;
+
+        Poll::Ready(Ok(AioEvent {
+            aio: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Waits for the kernel to post the `EVFILT_AIO` completion event for the in-flight
+    /// operation, returning an [`AioEvent`] that must be used to acknowledge (or decline to
+    /// acknowledge) the completion.
+    pub async fn ready(&self) -> io::Result<AioEvent<'_, T>> {
+        std::future::poll_fn(|cx| self.poll_ready(cx)).await
+    }
+}
+
+impl<'a, T: AioSource> AioEvent<'a, T> {
+    /// Indicates that the completion event has been consumed (e.g. the caller has reaped the
+    /// result with `aio_return`), and that tokio should wait for the next `EVFILT_AIO`
+    /// notification before returning ready again.
+    pub fn clear_ready(&mut self) {
+        if let Some(event) = self.event.take() {
+            self.aio.registration.clear_readiness(event);
+        }
+    }
+
+    /// Indicates that the event should be treated as still pending, keeping the cached readiness
+    /// asserted. This is a no-op used solely to satisfy the `#[must_use]` constraint on
+    /// [`AioEvent`].
+    pub fn retain_ready(&mut self) {
+        // no-op
+    }
+}
@@ -0,0 +1,8 @@
+//! BSD-specific I/O types.
+//!
+//! This module is only available on BSD-family targets (including macOS), where kqueue
+//! supports `EVFILT_AIO` completion notifications for POSIX async I/O.
+
+mod aio;
+
+pub use aio::{Aio, AioEvent, AioSource};
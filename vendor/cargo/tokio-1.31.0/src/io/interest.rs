@@ -0,0 +1,68 @@
+use std::ops;
+
+/// Readiness event interest.
+///
+/// Specifies the readiness events the reactor should report on for an I/O resource: whether it
+/// should report readability, writability, out-of-band/priority data, or some combination of
+/// these, formed by OR-ing interests together (e.g. `Interest::READABLE | Interest::WRITABLE`).
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Interest(u8);
+
+const READABLE: u8 = 0b0001;
+const WRITABLE: u8 = 0b0010;
+const PRIORITY: u8 = 0b0100;
+
+impl Interest {
+    /// Interest in readable readiness.
+    pub const READABLE: Interest = Interest(READABLE);
+
+    /// Interest in writable readiness.
+    pub const WRITABLE: Interest = Interest(WRITABLE);
+
+    /// Interest in out-of-band/priority readiness (`EPOLLPRI` on Linux).
+    ///
+    /// Not all platforms support priority readiness; on platforms that don't, registering an
+    /// I/O resource with this interest surfaces an [`io::Error`](std::io::Error) rather than
+    /// silently degrading to ordinary readability.
+    pub const PRIORITY: Interest = Interest(PRIORITY);
+
+    /// Returns true if the interest includes readable readiness.
+    #[inline]
+    pub const fn is_readable(self) -> bool {
+        self.0 & READABLE != 0
+    }
+
+    /// Returns true if the interest includes writable readiness.
+    #[inline]
+    pub const fn is_writable(self) -> bool {
+        self.0 & WRITABLE != 0
+    }
+
+    /// Returns true if the interest includes priority readiness.
+    #[inline]
+    pub const fn is_priority(self) -> bool {
+        self.0 & PRIORITY != 0
+    }
+
+    /// Combines this interest with `other`.
+    #[inline]
+    pub const fn add(self, other: Interest) -> Interest {
+        Interest(self.0 | other.0)
+    }
+}
+
+impl ops::BitOr for Interest {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Interest) -> Self {
+        self.add(other)
+    }
+}
+
+impl ops::BitOrAssign for Interest {
+    #[inline]
+    fn bitor_assign(&mut self, other: Interest) {
+        self.0 |= other.0;
+    }
+}
@@ -0,0 +1,37 @@
+//! Asynchronous I/O.
+//!
+//! This module is the home of [`AsyncFd`](unix::AsyncFd) and the platform-specific I/O helpers
+//! that sit alongside it, registering with the reactor directly rather than through `AsyncFd`:
+//! [`bsd::Aio`] for kqueue `EVFILT_AIO` completion notifications, and
+//! [`windows::AsyncSocket`] for raw Windows socket handles.
+
+mod interest;
+mod ready;
+
+pub use interest::Interest;
+pub use ready::Ready;
+
+#[cfg(unix)]
+mod async_fd;
+
+#[cfg(unix)]
+pub mod unix {
+    //! Asynchronous IO structures for dealing with Unix-specific file descriptors.
+
+    pub use super::async_fd::{AsyncFd, AsyncFdReadyGuard, AsyncFdReadyMutGuard, TryIoError};
+}
+
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "tvos",
+    target_os = "watchos",
+))]
+pub mod bsd;
+
+#[cfg(windows)]
+pub mod windows;
@@ -0,0 +1,76 @@
+use std::ops;
+
+/// A set of readiness events.
+///
+/// Unlike [`Interest`](super::Interest), which describes what a caller wants to be notified
+/// about, `Ready` describes what the reactor actually observed.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Ready(u8);
+
+const READABLE: u8 = 0b0001;
+const WRITABLE: u8 = 0b0010;
+const PRIORITY: u8 = 0b0100;
+
+impl Ready {
+    /// The empty readiness set.
+    pub const EMPTY: Ready = Ready(0);
+
+    /// Readable readiness.
+    pub const READABLE: Ready = Ready(READABLE);
+
+    /// Writable readiness.
+    pub const WRITABLE: Ready = Ready(WRITABLE);
+
+    /// Out-of-band/priority readiness (`EPOLLPRI` on Linux).
+    pub const PRIORITY: Ready = Ready(PRIORITY);
+
+    /// Returns true if this readiness set is empty.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the readiness set contains readable readiness.
+    #[inline]
+    pub const fn is_readable(self) -> bool {
+        self.0 & READABLE != 0
+    }
+
+    /// Returns true if the readiness set contains writable readiness.
+    #[inline]
+    pub const fn is_writable(self) -> bool {
+        self.0 & WRITABLE != 0
+    }
+
+    /// Returns true if the readiness set contains priority readiness.
+    #[inline]
+    pub const fn is_priority(self) -> bool {
+        self.0 & PRIORITY != 0
+    }
+}
+
+impl ops::BitOr for Ready {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Ready) -> Self {
+        Ready(self.0 | other.0)
+    }
+}
+
+impl ops::BitOrAssign for Ready {
+    #[inline]
+    fn bitor_assign(&mut self, other: Ready) {
+        self.0 |= other.0;
+    }
+}
+
+impl ops::Sub for Ready {
+    type Output = Self;
+
+    /// Removes every event in `other` from this readiness set.
+    #[inline]
+    fn sub(self, other: Ready) -> Self {
+        Ready(self.0 & !other.0)
+    }
+}
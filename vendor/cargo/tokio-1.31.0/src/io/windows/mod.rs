@@ -0,0 +1,9 @@
+//! Windows-specific I/O types for raw sockets.
+//!
+//! This is the `cfg(windows)` counterpart to [`crate::io::unix::AsyncFd`], giving foreign
+//! socket types (e.g. ones created by third-party WinSock bindings not covered by
+//! [`crate::net`]) the same ability to be driven by the tokio reactor.
+
+mod socket;
+
+pub use socket::{AsyncSocket, AsyncSocketReadyGuard, AsyncSocketReadyMutGuard};
@@ -0,0 +1,301 @@
+use crate::io::{Interest, Ready};
+use crate::runtime::io::{ReadyEvent, Registration};
+use crate::runtime::scheduler;
+
+use mio::windows::SourceSocket;
+use std::io;
+use std::os::windows::io::{AsRawSocket, RawSocket};
+use std::task::{Context, Poll};
+
+/// Associates an IO object backed by a raw Windows socket handle with the tokio reactor,
+/// allowing for readiness to be polled.
+///
+/// This is the `cfg(windows)` counterpart to [`crate::io::unix::AsyncFd`]; see that type's
+/// documentation for the general readiness model (edge-triggered notifications, the
+/// must-clear-on-`WouldBlock` guard discipline, etc). `AsyncSocket` exists because, unlike on
+/// Unix where any `AsRawFd` value can be registered via `mio::unix::SourceFd`, wrapping a
+/// foreign `RawSocket` on Windows requires routing through `mio::windows::SourceSocket`.
+///
+/// The inner object is required to implement [`AsRawSocket`]. As with `AsyncFd`, the raw socket
+/// handle returned by [`AsRawSocket::as_raw_socket`] must not change while `AsyncSocket` owns
+/// the inner object.
+pub struct AsyncSocket<T: AsRawSocket> {
+    registration: Registration,
+    inner: Option<T>,
+}
+
+/// Represents an IO-ready event detected on a particular raw socket that has not yet been
+/// acknowledged. This is a `must_use` structure to help ensure that you do not forget to
+/// explicitly clear (or not clear) the event.
+///
+/// This type exposes an immutable reference to the underlying IO object.
+#[must_use = "You must explicitly choose whether to clear the readiness state by calling a method on AsyncSocketReadyGuard"]
+pub struct AsyncSocketReadyGuard<'a, T: AsRawSocket> {
+    async_socket: &'a AsyncSocket<T>,
+    event: Option<ReadyEvent>,
+}
+
+/// Represents an IO-ready event detected on a particular raw socket that has not yet been
+/// acknowledged. This is a `must_use` structure to help ensure that you do not forget to
+/// explicitly clear (or not clear) the event.
+///
+/// This type exposes a mutable reference to the underlying IO object.
+#[must_use = "You must explicitly choose whether to clear the readiness state by calling a method on AsyncSocketReadyMutGuard"]
+pub struct AsyncSocketReadyMutGuard<'a, T: AsRawSocket> {
+    async_socket: &'a mut AsyncSocket<T>,
+    event: Option<ReadyEvent>,
+}
+
+impl<T: AsRawSocket> AsyncSocket<T> {
+    /// Creates an `AsyncSocket` backed by (and taking ownership of) an object implementing
+    /// [`AsRawSocket`]. The backing socket handle is cached at the time of creation.
+    ///
+    /// Only configures the [`Interest::READABLE`] and [`Interest::WRITABLE`] interests.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no current reactor set, or if the `rt` feature flag is
+    /// not enabled.
+    #[track_caller]
+    pub fn new(inner: T) -> io::Result<Self> {
+        Self::with_interest(inner, Interest::READABLE | Interest::WRITABLE)
+    }
+
+    /// Creates an `AsyncSocket` backed by (and taking ownership of) an object implementing
+    /// [`AsRawSocket`], with a specific [`Interest`]. The backing socket handle is cached at the
+    /// time of creation.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no current reactor set, or if the `rt` feature flag is
+    /// not enabled.
+    #[track_caller]
+    pub fn with_interest(inner: T, interest: Interest) -> io::Result<Self> {
+        let handle = scheduler::Handle::current();
+        let socket = inner.as_raw_socket();
+
+        let registration = Registration::new_with_interest_and_handle(
+            &mut SourceSocket::from_raw_socket(socket),
+            interest,
+            handle,
+        )?;
+
+        Ok(Self {
+            registration,
+            inner: Some(inner),
+        })
+    }
+
+    /// Returns a shared reference to the backing object of this `AsyncSocket`.
+    pub fn get_ref(&self) -> &T {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Returns a mutable reference to the backing object of this `AsyncSocket`.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.as_mut().unwrap()
+    }
+
+    fn take_inner(&mut self) -> Option<T> {
+        let socket = self.inner.as_ref().map(AsRawSocket::as_raw_socket);
+
+        if let Some(socket) = socket {
+            let _ = self
+                .registration
+                .deregister(&mut SourceSocket::from_raw_socket(socket));
+        }
+
+        self.inner.take()
+    }
+
+    /// Deregisters this socket and returns ownership of the backing object.
+    pub fn into_inner(mut self) -> T {
+        self.take_inner().unwrap()
+    }
+
+    /// Polls for read readiness. See [`AsyncFd::poll_read_ready`](
+    /// super::super::unix::AsyncFd::poll_read_ready) for the semantics.
+    pub fn poll_read_ready<'a>(
+        &'a self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncSocketReadyGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_read_ready(cx))?;
+
+        Poll::Ready(Ok(AsyncSocketReadyGuard {
+            async_socket: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Polls for write readiness. See [`AsyncFd::poll_write_ready`](
+    /// super::super::unix::AsyncFd::poll_write_ready) for the semantics.
+    pub fn poll_write_ready<'a>(
+        &'a self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncSocketReadyGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_write_ready(cx))?;
+
+        Poll::Ready(Ok(AsyncSocketReadyGuard {
+            async_socket: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Polls for read readiness, returning a guard with mutable access to the backing object.
+    /// See [`AsyncFd::poll_read_ready_mut`](
+    /// super::super::unix::AsyncFd::poll_read_ready_mut) for the semantics.
+    pub fn poll_read_ready_mut<'a>(
+        &'a mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncSocketReadyMutGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_read_ready(cx))?;
+
+        Poll::Ready(Ok(AsyncSocketReadyMutGuard {
+            async_socket: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Polls for write readiness, returning a guard with mutable access to the backing object.
+    /// See [`AsyncFd::poll_write_ready_mut`](
+    /// super::super::unix::AsyncFd::poll_write_ready_mut) for the semantics.
+    pub fn poll_write_ready_mut<'a>(
+        &'a mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<AsyncSocketReadyMutGuard<'a, T>>> {
+        let event = ready!(self.registration.poll_write_ready(cx))?;
+
+        Poll::Ready(Ok(AsyncSocketReadyMutGuard {
+            async_socket: self,
+            event: Some(event),
+        }))
+    }
+
+    /// Waits for any of the requested ready states.
+    pub async fn ready(&self, interest: Interest) -> io::Result<AsyncSocketReadyGuard<'_, T>> {
+        let event = self.registration.readiness(interest).await?;
+
+        Ok(AsyncSocketReadyGuard {
+            async_socket: self,
+            event: Some(event),
+        })
+    }
+
+    /// Waits for any of the requested ready states, returning a guard with mutable access to
+    /// the backing object.
+    pub async fn ready_mut(
+        &mut self,
+        interest: Interest,
+    ) -> io::Result<AsyncSocketReadyMutGuard<'_, T>> {
+        let event = self.registration.readiness(interest).await?;
+
+        Ok(AsyncSocketReadyMutGuard {
+            async_socket: self,
+            event: Some(event),
+        })
+    }
+
+    /// Waits for the socket to become readable.
+    pub async fn readable(&self) -> io::Result<AsyncSocketReadyGuard<'_, T>> {
+        self.ready(Interest::READABLE).await
+    }
+
+    /// Waits for the socket to become readable, returning a guard with mutable access to the
+    /// backing object.
+    #[allow(clippy::needless_lifetimes)] // The lifetime improves rustdoc rendering.
+    pub async fn readable_mut<'a>(&'a mut self) -> io::Result<AsyncSocketReadyMutGuard<'a, T>> {
+        self.ready_mut(Interest::READABLE).await
+    }
+
+    /// Waits for the socket to become writable.
+    pub async fn writable(&self) -> io::Result<AsyncSocketReadyGuard<'_, T>> {
+        self.ready(Interest::WRITABLE).await
+    }
+
+    /// Waits for the socket to become writable, returning a guard with mutable access to the
+    /// backing object.
+    #[allow(clippy::needless_lifetimes)] // The lifetime improves rustdoc rendering.
+    pub async fn writable_mut<'a>(&'a mut self) -> io::Result<AsyncSocketReadyMutGuard<'a, T>> {
+        self.ready_mut(Interest::WRITABLE).await
+    }
+}
+
+impl<T: AsRawSocket> AsRawSocket for AsyncSocket<T> {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.inner.as_ref().unwrap().as_raw_socket()
+    }
+}
+
+impl<T: AsRawSocket> Drop for AsyncSocket<T> {
+    fn drop(&mut self) {
+        let _ = self.take_inner();
+    }
+}
+
+impl<'a, T: AsRawSocket> AsyncSocketReadyGuard<'a, T> {
+    /// Indicates to tokio that the socket is no longer ready. See
+    /// [`AsyncFdReadyGuard::clear_ready`](super::super::unix::AsyncFdReadyGuard::clear_ready).
+    pub fn clear_ready(&mut self) {
+        if let Some(event) = self.event.take() {
+            self.async_socket.registration.clear_readiness(event);
+        }
+    }
+
+    /// Get the [`Ready`] value associated with this guard.
+    pub fn ready(&self) -> Ready {
+        match &self.event {
+            Some(event) => event.ready,
+            None => Ready::EMPTY,
+        }
+    }
+
+    /// Performs the provided IO operation, clearing readiness on `WouldBlock`. See
+    /// [`AsyncFdReadyGuard::try_io`](super::super::unix::AsyncFdReadyGuard::try_io).
+    pub fn try_io<R>(
+        &mut self,
+        f: impl FnOnce(&'a AsyncSocket<T>) -> io::Result<R>,
+    ) -> io::Result<R> {
+        let result = f(self.async_socket);
+
+        if let Err(e) = result.as_ref() {
+            if e.kind() == io::ErrorKind::WouldBlock {
+                self.clear_ready();
+            }
+        }
+
+        result
+    }
+}
+
+impl<'a, T: AsRawSocket> AsyncSocketReadyMutGuard<'a, T> {
+    /// Indicates to tokio that the socket is no longer ready.
+    pub fn clear_ready(&mut self) {
+        if let Some(event) = self.event.take() {
+            self.async_socket.registration.clear_readiness(event);
+        }
+    }
+
+    /// Get the [`Ready`] value associated with this guard.
+    pub fn ready(&self) -> Ready {
+        match &self.event {
+            Some(event) => event.ready,
+            None => Ready::EMPTY,
+        }
+    }
+
+    /// Performs the provided IO operation, clearing readiness on `WouldBlock`.
+    pub fn try_io<R>(
+        &mut self,
+        f: impl FnOnce(&mut AsyncSocket<T>) -> io::Result<R>,
+    ) -> io::Result<R> {
+        let result = f(self.async_socket);
+
+        if let Err(e) = result.as_ref() {
+            if e.kind() == io::ErrorKind::WouldBlock {
+                self.clear_ready();
+            }
+        }
+
+        result
+    }
+}